@@ -390,7 +390,7 @@ process_embedding_job(EmbeddingJob *job)
     StringSlice *texts = NULL;
     int max_id;
     int embedder_id, model_id;
-    EmbeddingBatch batch;
+    EmbeddingBatch batch = {0};
     int err;
 
     elog(LOG, "Starting to process job ID: %d (%s.%s.%s -> %s.%s.%s)",
@@ -455,6 +455,8 @@ process_embedding_job(EmbeddingJob *job)
     {
         elog(WARNING, "embedding generation failed for job %d (code=%d)",
              job->job_id, err);
+        if (batch.data != NULL)
+            free_embedding_batch(&batch);
         pfree(ids);
         return;
     }
@@ -464,6 +466,17 @@ process_embedding_job(EmbeddingJob *job)
     {
         elog(WARNING, "Job %d: Invalid batch result (n_vectors=%zu, dim=%zu, data=%p)",
              job->job_id, batch.n_vectors, batch.dim, batch.data);
+        if (batch.data != NULL)
+            free_embedding_batch(&batch);
+        pfree(ids);
+        return;
+    }
+
+    if (batch.n_vectors != (size_t)n_rows)
+    {
+        elog(WARNING, "Job %d: Expected %d embeddings, got %zu",
+             job->job_id, n_rows, batch.n_vectors);
+        free_embedding_batch(&batch);
         pfree(ids);
         return;
     }
@@ -41,7 +41,7 @@ Datum embed_text(PG_FUNCTION_ARGS)
         .n_text = 1
     };
 
-    EmbeddingBatch batch;
+    EmbeddingBatch batch = {0};
     int err = generate_embeddings(embedder_id, model_id, &input_data, &batch);
 
     if (err < 0) {
@@ -51,8 +51,10 @@ Datum embed_text(PG_FUNCTION_ARGS)
 
     if (batch.n_vectors != 1)
     {
+        size_t n_vectors = batch.n_vectors;
+
         free_embedding_batch(&batch);
-        elog(ERROR, "Expected 1 embedding, got %zu", batch.n_vectors);
+        elog(ERROR, "Expected 1 embedding, got %zu", n_vectors);
     }
 
     Vector *v = (Vector *)palloc(VECTOR_SIZE(batch.dim));
@@ -118,7 +120,7 @@ Datum embed_texts(PG_FUNCTION_ARGS)
         .n_text = nitems
     };
 
-    EmbeddingBatch batch;
+    EmbeddingBatch batch = {0};
     int err = generate_embeddings(embedder_id, model_id, &input_data, &batch);
 
     pfree(c_inputs);
@@ -128,6 +130,14 @@ Datum embed_texts(PG_FUNCTION_ARGS)
         elog(ERROR, "Embedding generation failed (code=%d)", err);
     }
 
+    if (batch.n_vectors != (size_t)nitems)
+    {
+        size_t n_vectors = batch.n_vectors;
+
+        free_embedding_batch(&batch);
+        elog(ERROR, "Expected %d embeddings, got %zu", nitems, n_vectors);
+    }
+
     Datum *vectors = palloc(sizeof(Datum) * batch.n_vectors);
     for (size_t i = 0; i < batch.n_vectors; i++)
     {
@@ -149,9 +159,11 @@ Datum embed_texts(PG_FUNCTION_ARGS)
         'd'
     );
 
+    size_t n_vectors = batch.n_vectors;
+
     free_embedding_batch(&batch);
 
-    for (size_t i = 0; i < batch.n_vectors; i++)
+    for (size_t i = 0; i < n_vectors; i++)
         pfree(DatumGetPointer(vectors[i]));
     pfree(vectors);
 
@@ -233,13 +245,25 @@ embed_texts_with_ids(PG_FUNCTION_ARGS)
             .n_text = n_texts
         };
 
-        EmbeddingBatch batch;
+        EmbeddingBatch batch = {0};
         int err = generate_embeddings(embedder_id, model_id, &input_data, &batch);
 
         pfree(c_inputs);
 
         if (err != 0)
+        {
+            if (batch.data != NULL)
+                free_embedding_batch(&batch);
             elog(ERROR, "embedding generation failed (code=%d)", err);
+        }
+
+        if (batch.n_vectors != (size_t)n_texts)
+        {
+            size_t n_vectors = batch.n_vectors;
+
+            free_embedding_batch(&batch);
+            elog(ERROR, "Expected %d embeddings, got %zu", n_texts, n_vectors);
+        }
 
         Vector **vectors = palloc(sizeof(Vector *) * batch.n_vectors);
         for (size_t i = 0; i < batch.n_vectors; i++)
@@ -326,7 +350,7 @@ Datum embed_image(PG_FUNCTION_ARGS)
         .n_text = 0
     };
 
-    EmbeddingBatch batch;
+    EmbeddingBatch batch = {0};
     int err = generate_embeddings(embedder_id, model_id, &input_data, &batch);
 
     if (err < 0) {
@@ -336,8 +360,10 @@ Datum embed_image(PG_FUNCTION_ARGS)
 
     if (batch.n_vectors != 1)
     {
+        size_t n_vectors = batch.n_vectors;
+
         free_embedding_batch(&batch);
-        elog(ERROR, "Expected 1 embedding, got %zu", batch.n_vectors);
+        elog(ERROR, "Expected 1 embedding, got %zu", n_vectors);
     }
 
     Vector *v = (Vector *)palloc(VECTOR_SIZE(batch.dim));
@@ -403,7 +429,7 @@ Datum embed_images(PG_FUNCTION_ARGS)
         .n_text = 0
     };
 
-    EmbeddingBatch batch;
+    EmbeddingBatch batch = {0};
     int err = generate_embeddings(embedder_id, model_id, &input_data, &batch);
 
     pfree(c_inputs);
@@ -413,6 +439,14 @@ Datum embed_images(PG_FUNCTION_ARGS)
         elog(ERROR, "Embedding generation failed (code=%d)", err);
     }
 
+    if (batch.n_vectors != (size_t)nitems)
+    {
+        size_t n_vectors = batch.n_vectors;
+
+        free_embedding_batch(&batch);
+        elog(ERROR, "Expected %d embeddings, got %zu", nitems, n_vectors);
+    }
+
     Datum *vectors = palloc(sizeof(Datum) * batch.n_vectors);
     for (size_t i = 0; i < batch.n_vectors; i++)
     {
@@ -434,9 +468,11 @@ Datum embed_images(PG_FUNCTION_ARGS)
         'd'
     );
 
+    size_t n_vectors = batch.n_vectors;
+
     free_embedding_batch(&batch);
 
-    for (size_t i = 0; i < batch.n_vectors; i++)
+    for (size_t i = 0; i < n_vectors; i++)
         pfree(DatumGetPointer(vectors[i]));
     pfree(vectors);
 
@@ -518,13 +554,25 @@ embed_images_with_ids(PG_FUNCTION_ARGS)
             .n_text = 0
         };
 
-        EmbeddingBatch batch;
+        EmbeddingBatch batch = {0};
         int err = generate_embeddings(embedder_id, model_id, &input_data, &batch);
 
         pfree(c_inputs);
 
         if (err != 0)
+        {
+            if (batch.data != NULL)
+                free_embedding_batch(&batch);
             elog(ERROR, "embedding generation failed (code=%d)", err);
+        }
+
+        if (batch.n_vectors != (size_t)n_images)
+        {
+            size_t n_vectors = batch.n_vectors;
+
+            free_embedding_batch(&batch);
+            elog(ERROR, "Expected %d embeddings, got %zu", n_images, n_vectors);
+        }
 
         Vector **vectors = palloc(sizeof(Vector *) * batch.n_vectors);
         for (size_t i = 0; i < batch.n_vectors; i++)
@@ -651,7 +699,7 @@ Datum embed_multimodal(PG_FUNCTION_ARGS)
         .n_text = n_texts
     };
 
-    EmbeddingBatch batch;
+    EmbeddingBatch batch = {0};
     int err = generate_embeddings(embedder_id, model_id, &input_data, &batch);
 
     if (c_images)
@@ -665,6 +713,14 @@ Datum embed_multimodal(PG_FUNCTION_ARGS)
         elog(ERROR, "Multimodal embedding generation failed (code=%d)", err);
     }
 
+    if (batch.n_vectors != (size_t)(n_images + n_texts))
+    {
+        size_t n_vectors = batch.n_vectors;
+
+        free_embedding_batch(&batch);
+        elog(ERROR, "Expected %d embeddings, got %zu", n_images + n_texts, n_vectors);
+    }
+
     Datum *vectors = palloc(sizeof(Datum) * batch.n_vectors);
     for (size_t i = 0; i < batch.n_vectors; i++)
     {
@@ -686,9 +742,11 @@ Datum embed_multimodal(PG_FUNCTION_ARGS)
         'd'
     );
 
+    size_t n_vectors = batch.n_vectors;
+
     free_embedding_batch(&batch);
 
-    for (size_t i = 0; i < batch.n_vectors; i++)
+    for (size_t i = 0; i < n_vectors; i++)
         pfree(DatumGetPointer(vectors[i]));
     pfree(vectors);
 